@@ -1,4 +1,9 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::rc::Rc;
+use wasm_bindgen::{closure::Closure, JsCast};
 use wasm_bindgen_test::*;
+use web_sys::{HtmlElement, KeyboardEvent, KeyboardEventInit, Node};
 use yew::prelude::*;
 use yew::{utils, App};
 
@@ -8,6 +13,23 @@ use yew::{utils, App};
 ///
 /// navbar
 ///
+/// Either pass `children` directly for a free-form menu, or pass `options` plus
+/// `on_select` to get an `<li>` rendered per option and a typed value emitted on click.
+/// When `options` is empty, `children` is rendered instead. `T` defaults to `String`,
+/// so existing `children`-only usages don't need to name it.
+///
+/// By default the dropdown tracks its own open/closed state. Pass `open` to control
+/// it from the parent instead; `on_toggle` is then called with the state an internal
+/// interaction would have set, so the parent can update `open` in response.
+///
+/// The trigger is focusable and exposes `aria-haspopup`/`aria-expanded`; Enter/Space
+/// toggles it (or selects the focused item, once one has focus), Escape closes it and
+/// returns focus to it, and ArrowDown/ArrowUp/Home/End move focus between `options`
+/// items. This keyboard and ARIA support only covers the `options` path: items passed
+/// as raw `children` aren't owned by this component, so they're not reachable by
+/// arrow-key navigation and don't get `role`/`tabindex` added to them. Prefer `options`
+/// plus `on_select` whenever the menu needs to be keyboard accessible.
+///
 /// ## Example
 ///
 /// ```rust
@@ -79,25 +101,23 @@ use yew::{utils, App};
 ///                            <span>{"Shop"}</span>
 ///                        </NavbarItem>
 ///                        <NavbarItem
-///                            onclick_signal=link.callback(move |_| Msg::ChangeMenu(String::from("About us")))>   
+///                            onclick_signal=link.callback(move |_| Msg::ChangeMenu(String::from("About us")))>
 ///                            <span>{"About us"}</span>
 ///                        </NavbarItem>
 ///                        <NavbarItem
-///                            onclick_signal=link.callback(move |_| Msg::ChangeMenu(String::from("Contact")))>   
+///                            onclick_signal=link.callback(move |_| Msg::ChangeMenu(String::from("Contact")))>
 ///                            <span>{"Contact"}</span>
 ///                        </NavbarItem>
-///                        <NavbarDropdown main_content=html!{
+///                        <NavbarDropdown
+///                          trigger=Trigger::Click
+///                          main_content=html!{
 ///                           <span>{menu}<ControllerAssets
 ///                             icon=ControllerIcon::ChevronDown
 ///                             size=("20".to_string(), "20".to_string())
 ///                           /></span>
-///                        }>
-///                          <NavbarDropdownItem
-///                            onclick_signal=link.callback(move |_: MouseEvent| Msg::ChangeType(String::from("menu 1".to_string())))>{"menu 1"}</NavbarDropdownItem>
-///                          <NavbarDropdownItem
-///                            onclick_signal=link.callback(move |_: MouseEvent| Msg::ChangeType(String::from("menu 2".to_string())))>{"menu 2"}</NavbarDropdownItem>
-///                          <NavbarDropdownItem
-///                            onclick_signal=link.callback(move |_: MouseEvent| Msg::ChangeType(String::from("menu 3".to_string())))>{"menu 3"}</NavbarDropdownItem>
+///                        }
+///                          options=vec![String::from("menu 1"), String::from("menu 2"), String::from("menu 3")]
+///                          on_select=link.callback(Msg::ChangeMenu)>
 ///                        </NavbarDropdown>
 ///                    </NavbarContainer>
 ///              </Navbar>
@@ -105,14 +125,66 @@ use yew::{utils, App};
 ///     }
 /// }
 /// ```
-pub struct NavbarDropdown {
-    props: Props,
+pub struct NavbarDropdown<T: Clone + Display + PartialEq + 'static = String> {
+    props: Props<T>,
     show: bool,
     link: ComponentLink<Self>,
+    node_ref: NodeRef,
+    main_content_ref: NodeRef,
+    item_refs: Vec<NodeRef>,
+    focused_index: Option<usize>,
+    return_focus_to_trigger: bool,
+    outside_click_listener: Option<Closure<dyn FnMut(web_sys::MouseEvent)>>,
+}
+
+/// The way a `NavbarDropdown` is opened and closed
+#[derive(Clone, PartialEq)]
+pub enum Trigger {
+    /// opens on mouse over and closes on mouse leave or click, the historic behavior
+    Hover,
+    /// opens and closes on clicks instead of mouse over/leave, so it also works with touch;
+    /// keyboard handling is the same in both modes
+    Click,
+}
+
+impl Default for Trigger {
+    fn default() -> Self {
+        Trigger::Hover
+    }
+}
+
+/// Where the items list opens relative to the `.main-content` trigger
+#[derive(Clone, PartialEq)]
+pub enum Placement {
+    /// items list opens below the trigger, the historic behavior
+    Down,
+    /// items list opens above the trigger, for navbars fixed to the bottom
+    Up,
+    /// items list aligns to the left edge of the trigger
+    Left,
+    /// items list aligns to the right edge of the trigger
+    Right,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Placement::Down
+    }
+}
+
+impl Placement {
+    fn class_name(&self) -> &'static str {
+        match self {
+            Placement::Down => "dropdown-down",
+            Placement::Up => "dropdown-up",
+            Placement::Left => "dropdown-left",
+            Placement::Right => "dropdown-right",
+        }
+    }
 }
 
 #[derive(Clone, Properties, PartialEq)]
-pub struct Props {
+pub struct Props<T: Clone + Display + PartialEq + 'static = String> {
     /// clickeable content to show the dropdown. Required
     pub main_content: Html,
     /// General property to add custom class styles
@@ -124,91 +196,411 @@ pub struct Props {
     /// General property to add custom id
     #[prop_or_default]
     pub id: String,
+    /// how the dropdown is opened and closed
+    #[prop_or_default]
+    pub trigger: Trigger,
+    /// where the items list opens relative to the trigger
+    #[prop_or_default]
+    pub placement: Placement,
+    /// options rendered as `<li>` items; when empty, `children` is rendered instead
+    #[prop_or_default]
+    pub options: Vec<T>,
+    /// called with the selected option when an `options` item is clicked
+    #[prop_or_default]
+    pub on_select: Callback<T>,
+    /// when `Some`, the dropdown is controlled and renders from this value instead of
+    /// its internal state; when `None` it keeps track of its own open/closed state
+    #[prop_or_default]
+    pub open: Option<bool>,
+    /// called with the new open state whenever an internal interaction would have
+    /// toggled the dropdown, whether controlled or not
+    #[prop_or_default]
+    pub on_toggle: Callback<bool>,
     pub children: Children,
 }
 
-pub enum Msg {
+pub enum Msg<T> {
     ShowDropdown,
     HideDropdown,
+    ToggleDropdown,
+    SelectOption(T),
+    KeyDown(KeyboardEvent),
+}
+
+impl<T: Clone + Display + PartialEq + 'static> NavbarDropdown<T> {
+    /// the current open state, taking the controlled `open` prop into account
+    fn is_open(&self) -> bool {
+        self.props.open.unwrap_or(self.show)
+    }
+
+    /// applies an internal interaction's desired open state: mutates and re-renders
+    /// when uncontrolled, or just notifies the parent through `on_toggle` when controlled.
+    /// Closing always clears `focused_index`, so a stale focused item from a previous
+    /// open never leaks into the next Enter/Space keydown.
+    fn set_open(&mut self, open: bool) -> ShouldRender {
+        if !open {
+            self.focused_index = None;
+        }
+
+        if self.props.open.is_some() {
+            if self.is_open() != open {
+                self.props.on_toggle.emit(open);
+            }
+            false
+        } else {
+            if self.show != open {
+                self.show = open;
+                self.props.on_toggle.emit(open);
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    fn ensure_item_refs_len(&mut self, len: usize) {
+        if self.item_refs.len() != len {
+            self.item_refs = (0..len).map(|_| NodeRef::default()).collect();
+        }
+    }
+
+    /// handles a `keydown` bubbled up from the trigger or an item: Enter/Space selects
+    /// the focused item if one has keyboard focus, otherwise toggles the trigger; Escape
+    /// closes and returns focus to the trigger; the arrow keys and Home/End move focus
+    /// between items (only possible for `options`-rendered items, see `item_refs`)
+    fn handle_keydown(&mut self, event: KeyboardEvent) -> ShouldRender {
+        let options_len = self.props.options.len();
+
+        match event.key().as_str() {
+            "Enter" | " " => {
+                event.prevent_default();
+
+                let focused_option = self
+                    .focused_index
+                    .filter(|_| self.is_open())
+                    .and_then(|index| self.props.options.get(index).cloned());
+
+                match focused_option {
+                    Some(option) => {
+                        self.props.on_select.emit(option);
+                        self.set_open(false);
+                    }
+                    None => {
+                        let next = !self.is_open();
+                        self.set_open(next);
+                        self.focused_index = if next && options_len > 0 {
+                            Some(0)
+                        } else {
+                            None
+                        };
+                    }
+                }
+                true
+            }
+            "Escape" => {
+                event.prevent_default();
+                self.set_open(false);
+                self.return_focus_to_trigger = true;
+                true
+            }
+            "ArrowDown" if options_len > 0 => {
+                event.prevent_default();
+                self.set_open(true);
+                self.focused_index = Some(match self.focused_index {
+                    Some(index) => (index + 1) % options_len,
+                    None => 0,
+                });
+                true
+            }
+            "ArrowUp" if options_len > 0 => {
+                event.prevent_default();
+                self.set_open(true);
+                self.focused_index = Some(match self.focused_index {
+                    Some(0) | None => options_len - 1,
+                    Some(index) => index - 1,
+                });
+                true
+            }
+            "Home" if self.is_open() && options_len > 0 => {
+                event.prevent_default();
+                self.focused_index = Some(0);
+                true
+            }
+            "End" if self.is_open() && options_len > 0 => {
+                event.prevent_default();
+                self.focused_index = Some(options_len - 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// attaches the outside-click listener when `trigger` is `Click` and it isn't
+    /// already attached, and detaches it when `trigger` isn't `Click` anymore; safe to
+    /// call on every render so it keeps tracking the live `trigger` prop
+    fn sync_outside_click_listener(&mut self) {
+        let should_be_attached = self.props.trigger == Trigger::Click;
+        let is_attached = self.outside_click_listener.is_some();
+
+        if should_be_attached && !is_attached {
+            let node_ref = self.node_ref.clone();
+            let link = self.link.clone();
+
+            let closure = Closure::wrap(Box::new(move |event: web_sys::MouseEvent| {
+                let clicked_inside = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Node>().ok())
+                    .and_then(|target_node| {
+                        node_ref
+                            .get()
+                            .map(|element| element.contains(Some(&target_node)))
+                    })
+                    .unwrap_or(false);
+
+                if !clicked_inside {
+                    link.send_message(Msg::HideDropdown);
+                }
+            }) as Box<dyn FnMut(web_sys::MouseEvent)>);
+
+            utils::document()
+                .add_event_listener_with_callback("mousedown", closure.as_ref().unchecked_ref())
+                .expect("failed to attach the outside click listener");
+
+            self.outside_click_listener = Some(closure);
+        } else if !should_be_attached {
+            if let Some(closure) = self.outside_click_listener.take() {
+                let _ = utils::document().remove_event_listener_with_callback(
+                    "mousedown",
+                    closure.as_ref().unchecked_ref(),
+                );
+            }
+        }
+    }
+
+    /// moves the actual DOM focus to match `focused_index`/`return_focus_to_trigger`;
+    /// called after every render since focus can only be set once elements exist
+    fn apply_focus(&mut self) {
+        if self.is_open() {
+            if let Some(element) = self
+                .focused_index
+                .and_then(|index| self.item_refs.get(index))
+                .and_then(|item_ref| item_ref.cast::<HtmlElement>())
+            {
+                let _ = element.focus();
+            }
+        } else if self.return_focus_to_trigger {
+            self.return_focus_to_trigger = false;
+            if let Some(element) = self.main_content_ref.cast::<HtmlElement>() {
+                let _ = element.focus();
+            }
+        }
+    }
 }
 
-impl Component for NavbarDropdown {
-    type Message = Msg;
-    type Properties = Props;
+impl<T: Clone + Display + PartialEq + 'static> Component for NavbarDropdown<T> {
+    type Message = Msg<T>;
+    type Properties = Props<T>;
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let item_refs = (0..props.options.len())
+            .map(|_| NodeRef::default())
+            .collect();
+
         Self {
             props,
             link,
             show: false,
+            node_ref: NodeRef::default(),
+            main_content_ref: NodeRef::default(),
+            item_refs,
+            focused_index: None,
+            return_focus_to_trigger: false,
+            outside_click_listener: None,
         }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
-            Msg::ShowDropdown => {
-                self.show = true;
+            Msg::ShowDropdown => self.set_open(true),
+            Msg::HideDropdown => self.set_open(false),
+            Msg::ToggleDropdown => {
+                let next = !self.is_open();
+                self.set_open(next)
             }
-            Msg::HideDropdown => {
-                self.show = false;
+            Msg::SelectOption(option) => {
+                self.props.on_select.emit(option);
+                self.set_open(false)
             }
+            Msg::KeyDown(event) => self.handle_keydown(event),
         }
-        true
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
         if self.props != props {
+            self.ensure_item_refs_len(props.options.len());
             self.props = props;
             return true;
         }
         false
     }
 
+    fn rendered(&mut self, _first_render: bool) {
+        self.sync_outside_click_listener();
+        self.apply_focus();
+    }
+
     fn view(&self) -> Html {
+        let is_click_trigger = self.props.trigger == Trigger::Click;
+
+        let onmouseover = if is_click_trigger {
+            noop_callback()
+        } else {
+            self.link.callback(|_| Msg::ShowDropdown)
+        };
+
+        let onmouseleave = if is_click_trigger {
+            noop_callback()
+        } else {
+            self.link.callback(|_| Msg::HideDropdown)
+        };
+
+        let main_content_onclick = if is_click_trigger {
+            self.link.callback(|e: MouseEvent| {
+                e.stop_propagation();
+                Msg::ToggleDropdown
+            })
+        } else {
+            noop_callback()
+        };
+
+        let main_content = html! {
+            <div
+                ref=self.main_content_ref.clone()
+                class="main-content"
+                tabindex="0"
+                aria-haspopup="true"
+                aria-expanded=self.is_open().to_string()
+                onclick=main_content_onclick
+                >{self.props.main_content.clone()}</div>
+        };
+        let items = get_items(
+            self.is_open(),
+            &self.props.options,
+            self.props.children.clone(),
+            &self.link,
+            &self.item_refs,
+        );
+        let ordered_content = if self.props.placement == Placement::Up {
+            vec![items, main_content]
+        } else {
+            vec![main_content, items]
+        };
+
         html! {
             <div
+                ref=self.node_ref.clone()
                 class=("navbar-dropdown", if self.props.active {
                     "active"
                 } else {
                     ""
-                }, self.props.class_name.clone())
+                }, self.props.placement.class_name(), self.props.class_name.clone())
                 id=self.props.id
-                onmouseover=self.link.callback(|_| Msg::ShowDropdown)
-                onmouseleave=self.link.callback(|_| Msg::HideDropdown)
+                onmouseover=onmouseover
+                onmouseleave=onmouseleave
                 onclick=self.link.callback(|_| Msg::HideDropdown)
+                onkeydown=self.link.callback(Msg::KeyDown)
                 >
-                <div class="main-content">{self.props.main_content.clone()}</div>
-                {get_items(self.show, self.props.children.clone())}
+                {for ordered_content.into_iter()}
             </div>
         }
     }
 }
 
-fn get_items(show: bool, children: Children) -> Html {
-    if show {
+impl<T: Clone + Display + PartialEq + 'static> Drop for NavbarDropdown<T> {
+    fn drop(&mut self) {
+        if let Some(closure) = self.outside_click_listener.take() {
+            let _ = utils::document()
+                .remove_event_listener_with_callback("mousedown", closure.as_ref().unchecked_ref());
+        }
+    }
+}
+
+fn noop_callback<T: 'static>() -> Callback<T> {
+    Callback::from(|_| {})
+}
+
+/// dispatches a real, bubbling `keydown` with the given `key` on `target`, for tests
+fn dispatch_keydown(target: &HtmlElement, key: &str) {
+    let mut init = KeyboardEventInit::new();
+    init.key(key);
+    init.bubbles(true);
+    init.cancelable(true);
+
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+    target.dispatch_event(&event).unwrap();
+}
+
+fn get_items<T: Clone + Display + PartialEq + 'static>(
+    show: bool,
+    options: &[T],
+    children: Children,
+    link: &ComponentLink<NavbarDropdown<T>>,
+    item_refs: &[NodeRef],
+) -> Html {
+    if !show {
+        return html! {};
+    }
+
+    if options.is_empty() {
         html! {
-            <ul>
+            <ul role="menu">
                 {children.clone()}
             </ul>
         }
     } else {
-        html! {}
+        html! {
+            <ul role="menu">
+                {for options.iter().cloned().enumerate().map(|(index, option)| {
+                    let text = option.to_string();
+                    let onclick = link.callback(move |e: MouseEvent| {
+                        e.stop_propagation();
+                        Msg::SelectOption(option.clone())
+                    });
+                    let item_ref = item_refs.get(index).cloned().unwrap_or_default();
+
+                    html! {
+                        <li
+                            ref=item_ref
+                            role="menuitem"
+                            tabindex="-1"
+                            onclick=onclick
+                            >{text}</li>
+                    }
+                })}
+            </ul>
+        }
     }
 }
 
 #[wasm_bindgen_test]
 fn should_create_navbar_dropdown_container() {
-    let navbar_dropdown_container_props = Props {
+    let navbar_dropdown_container_props: Props<String> = Props {
         main_content: html! {<div id="test">{"test"}</div>},
         active: false,
         class_name: String::from("class-test"),
         id: String::from("id-test"),
+        trigger: Trigger::Hover,
+        placement: Placement::Down,
+        options: Vec::new(),
+        on_select: noop_callback(),
+        open: None,
+        on_toggle: noop_callback(),
         children: Children::new(vec![html! {
             <div id="item">{"Item"}</div>
         }]),
     };
 
-    let navbar_dropdown_container: App<NavbarDropdown> = App::new();
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
 
     navbar_dropdown_container.mount_with_props(
         utils::document().get_element_by_id("output").unwrap(),
@@ -218,3 +610,346 @@ fn should_create_navbar_dropdown_container() {
     let content_element = utils::document().get_element_by_id("test").unwrap();
     assert_eq!(content_element.text_content().unwrap(), "test".to_string());
 }
+
+#[wasm_bindgen_test]
+fn should_toggle_on_click_trigger() {
+    let navbar_dropdown_container_props: Props<String> = Props {
+        main_content: html! {<div id="click-trigger-test">{"test"}</div>},
+        active: false,
+        class_name: String::from("class-test"),
+        id: String::from("id-test"),
+        trigger: Trigger::Click,
+        placement: Placement::Down,
+        options: Vec::new(),
+        on_select: noop_callback(),
+        open: None,
+        on_toggle: noop_callback(),
+        children: Children::new(vec![html! {
+            <div id="click-trigger-item">{"Item"}</div>
+        }]),
+    };
+
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
+
+    navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let trigger_element: HtmlElement = utils::document()
+        .get_element_by_id("click-trigger-test")
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+
+    assert!(utils::document()
+        .get_element_by_id("click-trigger-item")
+        .is_none());
+
+    trigger_element.click();
+    assert!(utils::document()
+        .get_element_by_id("click-trigger-item")
+        .is_some());
+
+    trigger_element.click();
+    assert!(utils::document()
+        .get_element_by_id("click-trigger-item")
+        .is_none());
+}
+
+#[wasm_bindgen_test]
+fn should_select_option() {
+    let selected_option = Rc::new(RefCell::new(None));
+    let selected_option_clone = selected_option.clone();
+
+    let navbar_dropdown_container_props: Props<String> = Props {
+        main_content: html! {<div id="options-test">{"test"}</div>},
+        active: false,
+        class_name: String::from("class-test"),
+        id: String::from("id-test"),
+        trigger: Trigger::Hover,
+        placement: Placement::Down,
+        options: vec![String::from("menu 1"), String::from("menu 2")],
+        on_select: Callback::from(move |option| {
+            *selected_option_clone.borrow_mut() = Some(option);
+        }),
+        open: Some(true),
+        on_toggle: noop_callback(),
+        children: Children::new(vec![]),
+    };
+
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
+
+    navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let first_option: HtmlElement = utils::document()
+        .query_selector("li[role=\"menuitem\"]")
+        .unwrap()
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+
+    first_option.click();
+
+    assert_eq!(*selected_option.borrow(), Some(String::from("menu 1")));
+}
+
+#[wasm_bindgen_test]
+fn should_render_from_controlled_open_prop() {
+    let toggled_to = Rc::new(RefCell::new(None));
+    let toggled_to_clone = toggled_to.clone();
+
+    let navbar_dropdown_container_props: Props<String> = Props {
+        main_content: html! {<div id="controlled-test">{"test"}</div>},
+        active: false,
+        class_name: String::from("class-test"),
+        id: String::from("id-test"),
+        trigger: Trigger::Click,
+        placement: Placement::Down,
+        options: Vec::new(),
+        on_select: noop_callback(),
+        open: Some(false),
+        on_toggle: Callback::from(move |open| {
+            *toggled_to_clone.borrow_mut() = Some(open);
+        }),
+        children: Children::new(vec![html! {
+            <div id="controlled-item">{"Item"}</div>
+        }]),
+    };
+
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
+
+    navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let trigger_element: HtmlElement = utils::document()
+        .get_element_by_id("controlled-test")
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+
+    trigger_element.click();
+
+    // open is controlled and stayed at Some(false), so the click must not have
+    // opened the dropdown on its own
+    assert!(utils::document()
+        .get_element_by_id("controlled-item")
+        .is_none());
+    // but the parent must have been notified of the state the click would have set
+    assert_eq!(*toggled_to.borrow(), Some(true));
+}
+
+#[wasm_bindgen_test]
+fn should_fire_on_toggle_once_when_selecting_an_option_in_controlled_mode() {
+    let toggle_count = Rc::new(RefCell::new(0));
+    let toggle_count_clone = toggle_count.clone();
+
+    let navbar_dropdown_container_props: Props<String> = Props {
+        main_content: html! {<div id="controlled-select-test">{"test"}</div>},
+        active: false,
+        class_name: String::from("class-test"),
+        id: String::from("id-test"),
+        trigger: Trigger::Click,
+        placement: Placement::Down,
+        options: vec![String::from("menu 1"), String::from("menu 2")],
+        on_select: noop_callback(),
+        open: Some(true),
+        on_toggle: Callback::from(move |_| {
+            *toggle_count_clone.borrow_mut() += 1;
+        }),
+        children: Children::new(vec![]),
+    };
+
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
+
+    navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let first_option: HtmlElement = utils::document()
+        .query_selector("li[role=\"menuitem\"]")
+        .unwrap()
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+
+    first_option.click();
+
+    // the item's onclick stops propagation, so the container's own onclick=HideDropdown
+    // doesn't also run; on_toggle must only fire once for the one user click
+    assert_eq!(*toggle_count.borrow(), 1);
+}
+
+#[wasm_bindgen_test]
+fn should_render_with_up_placement() {
+    let navbar_dropdown_container_props: Props<String> = Props {
+        main_content: html! {<div id="placement-test">{"test"}</div>},
+        active: false,
+        class_name: String::from("class-test"),
+        id: String::from("id-test"),
+        trigger: Trigger::Hover,
+        placement: Placement::Up,
+        options: Vec::new(),
+        on_select: noop_callback(),
+        open: None,
+        on_toggle: noop_callback(),
+        children: Children::new(vec![html! {
+            <div id="item">{"Item"}</div>
+        }]),
+    };
+
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
+
+    navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let container_element = utils::document().get_element_by_id("id-test").unwrap();
+    assert!(container_element.class_list().contains("dropdown-up"));
+}
+
+#[wasm_bindgen_test]
+fn should_expose_aria_attributes_on_the_trigger() {
+    let navbar_dropdown_container_props: Props<String> = Props {
+        main_content: html! {<span id="aria-test">{"test"}</span>},
+        active: false,
+        class_name: String::from("class-test"),
+        id: String::from("id-test"),
+        trigger: Trigger::Hover,
+        placement: Placement::Down,
+        options: Vec::new(),
+        on_select: noop_callback(),
+        open: None,
+        on_toggle: noop_callback(),
+        children: Children::new(vec![html! {
+            <div id="item">{"Item"}</div>
+        }]),
+    };
+
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
+
+    navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let trigger_element = utils::document()
+        .get_element_by_id("aria-test")
+        .unwrap()
+        .parent_element()
+        .unwrap();
+    assert_eq!(trigger_element.get_attribute("tabindex").unwrap(), "0");
+    assert_eq!(
+        trigger_element.get_attribute("aria-haspopup").unwrap(),
+        "true"
+    );
+    assert_eq!(
+        trigger_element.get_attribute("aria-expanded").unwrap(),
+        "false"
+    );
+}
+
+#[wasm_bindgen_test]
+fn should_select_option_via_keyboard() {
+    let selected_option = Rc::new(RefCell::new(None));
+    let selected_option_clone = selected_option.clone();
+
+    let navbar_dropdown_container_props: Props<String> = Props {
+        main_content: html! {<div id="keyboard-select-test">{"test"}</div>},
+        active: false,
+        class_name: String::from("class-test"),
+        id: String::from("id-test"),
+        trigger: Trigger::Click,
+        placement: Placement::Down,
+        options: vec![String::from("menu 1"), String::from("menu 2")],
+        on_select: Callback::from(move |option| {
+            *selected_option_clone.borrow_mut() = Some(option);
+        }),
+        open: None,
+        on_toggle: noop_callback(),
+        children: Children::new(vec![]),
+    };
+
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
+
+    navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let trigger_element: HtmlElement = utils::document()
+        .get_element_by_id("keyboard-select-test")
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+
+    assert!(utils::document()
+        .query_selector("li[role=\"menuitem\"]")
+        .unwrap()
+        .is_none());
+
+    // Enter on the trigger opens the menu and focuses the first item
+    dispatch_keydown(&trigger_element, "Enter");
+    assert!(utils::document()
+        .query_selector("li[role=\"menuitem\"]")
+        .unwrap()
+        .is_some());
+
+    // ArrowDown moves focus to the second item
+    dispatch_keydown(&trigger_element, "ArrowDown");
+    // Enter now selects the focused item instead of toggling the trigger again
+    dispatch_keydown(&trigger_element, "Enter");
+
+    assert_eq!(*selected_option.borrow(), Some(String::from("menu 2")));
+    assert!(utils::document()
+        .query_selector("li[role=\"menuitem\"]")
+        .unwrap()
+        .is_none());
+}
+
+#[wasm_bindgen_test]
+fn should_return_focus_to_trigger_on_escape() {
+    let navbar_dropdown_container_props: Props<String> = Props {
+        main_content: html! {<div id="escape-test">{"test"}</div>},
+        active: false,
+        class_name: String::from("class-test"),
+        id: String::from("id-test"),
+        trigger: Trigger::Click,
+        placement: Placement::Down,
+        options: vec![String::from("menu 1")],
+        on_select: noop_callback(),
+        open: None,
+        on_toggle: noop_callback(),
+        children: Children::new(vec![]),
+    };
+
+    let navbar_dropdown_container: App<NavbarDropdown<String>> = App::new();
+
+    navbar_dropdown_container.mount_with_props(
+        utils::document().get_element_by_id("output").unwrap(),
+        navbar_dropdown_container_props,
+    );
+
+    let content_element = utils::document().get_element_by_id("escape-test").unwrap();
+    let trigger_element: HtmlElement = content_element.clone().dyn_into().unwrap();
+    let main_content_element = content_element.parent_element().unwrap();
+
+    dispatch_keydown(&trigger_element, "Enter");
+    dispatch_keydown(&trigger_element, "Escape");
+
+    assert!(utils::document()
+        .query_selector("li[role=\"menuitem\"]")
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        utils::document().active_element().unwrap(),
+        main_content_element
+    );
+}